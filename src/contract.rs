@@ -103,17 +103,45 @@ impl Contract {
         this
     }
 
-    // @todo add optional value. AND it take constructor args
-    pub fn deploy(
+    pub fn deploy(evm: &RevmProvider, deployer: Address, bincode: Bytes) -> anyhow::Result<Receipt> {
+        let mut tx = TxEnv::default();
+        tx.caller = deployer.into();
+        tx.transact_to = TransactTo::create();
+        tx.data = bincode.to_vec().into();
+
+        evm.deploy(tx)
+    }
+
+    /// Deploy a contract whose constructor takes arguments and/or is
+    /// payable. `constructor_args` are ABI-encoded and appended to the
+    /// creation bytecode, matching how the constructor is actually invoked
+    /// on-chain.
+    pub fn deploy_with_args<T: Tokenize>(
+        &self,
         evm: &RevmProvider,
         deployer: Address,
-        bincode: Bytes,
-    ) -> anyhow::Result<(Address, u64)> {
+        bytecode: Bytes,
+        constructor_args: T,
+        value: Option<U256>,
+    ) -> anyhow::Result<Receipt> {
+        let constructor = self
+            .contract
+            .abi()
+            .constructor()
+            .ok_or_else(|| anyhow!("contract has no constructor"))?;
+
+        let init_code = constructor
+            .encode_input(bytecode.to_vec(), &constructor_args.into_tokens())
+            .map_err(|e| anyhow!("{:}", e))?;
+
         let mut tx = TxEnv::default();
         tx.caller = deployer.into();
         tx.transact_to = TransactTo::create();
-        tx.data = bincode.to_vec().into();
-        //tx.value = U256::zero().into();
+        tx.data = init_code.into();
+
+        if let Some(value) = value {
+            tx.value = value;
+        }
 
         evm.deploy(tx)
     }
@@ -125,7 +153,7 @@ impl Contract {
         name: &str,
         args: T,
         caller: Address,
-    ) -> anyhow::Result<(D, u64, Vec<Log>)>
+    ) -> anyhow::Result<(D, Receipt)>
     where
         T: Tokenize,
         D: Detokenize,
@@ -141,12 +169,17 @@ impl Contract {
         tx.transact_to = TransactTo::Call(self.address.unwrap().into());
         tx.data = encoded.to_vec().into(); //revm::precompile::Bytes::from(encoded.to_vec());
 
-        evm.call(tx)
-            .and_then(|(bits, gas_used, logs)| {
-                let v = self.contract.decode_output::<D, _>(name, bits)?;
-                Ok((v, gas_used, logs))
-            })
-            .map_err(|e| anyhow!("{:}", e))
+        let receipt = evm.call(tx)?;
+        if receipt.failure.is_some() {
+            return Err(ContractCallError { receipt }.into());
+        }
+
+        let v = self
+            .contract
+            .decode_output::<D, _>(name, receipt.output.clone())
+            .map_err(|e| anyhow!("{:}", e))?;
+
+        Ok((v, receipt))
     }
 
     /// Send a transaction
@@ -157,7 +190,7 @@ impl Contract {
         args: T,
         caller: Address,
         value: Option<U256>,
-    ) -> anyhow::Result<(D, u64, Vec<Log>)>
+    ) -> anyhow::Result<(D, Receipt)>
     where
         T: Tokenize,
         D: Detokenize,
@@ -168,7 +201,6 @@ impl Contract {
 
         let encoded = self.contract.encode(name, args)?;
 
-        // @todo estimate gas cost for tx
         let mut tx = TxEnv::default();
         tx.caller = caller.into();
         tx.transact_to = TransactTo::Call(self.address.unwrap().into());
@@ -178,12 +210,64 @@ impl Contract {
             tx.value = value.unwrap().into();
         }
 
-        evm.send(tx)
-            .and_then(|(bits, gas_used, logs)| {
-                let v = self.contract.decode_output::<D, _>(name, bits)?;
-                Ok((v, gas_used, logs))
-            })
-            .map_err(|e| anyhow!("oops {:}", e))
+        let receipt = evm.send(tx)?;
+        if receipt.failure.is_some() {
+            return Err(ContractCallError { receipt }.into());
+        }
+
+        let v = self
+            .contract
+            .decode_output::<D, _>(name, receipt.output.clone())
+            .map_err(|e| anyhow!("oops {:}", e))?;
+
+        Ok((v, receipt))
+    }
+
+    /// Decode a single log into a typed event using this contract's ABI
+    pub fn decode_event<D: Detokenize>(&self, event_name: &str, log: &Log) -> anyhow::Result<D> {
+        let topics = log
+            .topics
+            .iter()
+            .map(|topic| ethers::types::H256::from(topic.0))
+            .collect();
+        let data = ethers::types::Bytes::from(log.data.to_vec());
+
+        self.contract
+            .decode_event::<D>(event_name, topics, data)
+            .map_err(|e| anyhow!("{:}", e))
+    }
+
+    /// Decode every log in `logs` that matches `event_name`, skipping any
+    /// that don't (e.g. events emitted by a different contract mid-call)
+    pub fn decode_events<D: Detokenize>(&self, event_name: &str, logs: &[Log]) -> Vec<D> {
+        logs.iter()
+            .filter_map(|log| self.decode_event(event_name, log).ok())
+            .collect()
+    }
+
+    /// Estimate the gas a call to `name` would use
+    pub fn estimate_gas<T>(
+        &self,
+        evm: &RevmProvider,
+        name: &str,
+        args: T,
+        caller: Address,
+    ) -> anyhow::Result<u64>
+    where
+        T: Tokenize,
+    {
+        if self.address.is_none() {
+            bail!("missing contract address");
+        }
+
+        let encoded = self.contract.encode(name, args)?;
+
+        let mut tx = TxEnv::default();
+        tx.caller = caller.into();
+        tx.transact_to = TransactTo::Call(self.address.unwrap().into());
+        tx.data = encoded.to_vec().into();
+
+        evm.estimate_gas(tx)
     }
 }
 
@@ -204,4 +288,33 @@ mod tests {
     fn metadata_panics_on_missing_file() {
         let _ = ContractMetadata::from("./nope.json");
     }
+
+    #[test]
+    fn deploy_with_args_encodes_constructor_args_and_value() {
+        let provider = RevmProvider::new();
+        let deployer = Address::from_low_u64_be(1);
+        provider
+            .create_account(deployer, Some(U256::from(1)))
+            .unwrap();
+
+        let abi = parse_abi(&["constructor(uint64 initial)"]).unwrap();
+        let contract = Contract::from(abi);
+
+        // Minimal init code (PUSH1 0 PUSH1 0 RETURN) that returns empty
+        // runtime code regardless of what's appended after it — enough to
+        // confirm a deploy with an encoded constructor arg appended goes
+        // through and the value lands on the created account, without
+        // needing a compiled fixture.
+        let bytecode = Bytes::from(vec![0x60, 0x00, 0x60, 0x00, 0xf3]);
+
+        let receipt = contract
+            .deploy_with_args(&provider, deployer, bytecode, (7u64,), Some(U256::from(1)))
+            .expect("deploy_with_args should succeed");
+
+        assert!(receipt.success());
+        let created = receipt
+            .created_address
+            .expect("a create call should report the created address");
+        assert_eq!(provider.balance_of(created), U256::from(1));
+    }
 }
@@ -0,0 +1,211 @@
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::{BlockId, BlockNumber},
+};
+use revm::{
+    db::DatabaseRef,
+    primitives::{AccountInfo, Address, Bytecode, Bytes, B256, U256},
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{mpsc, OnceLock, RwLock};
+
+/// Lets `EthVmInner` ask a `DatabaseRef` whether anything went wrong
+/// servicing the in-flight call. `DatabaseRef::Error` can't carry that for
+/// forked state, since revm's `CacheDB` expects `Infallible` there, so this
+/// is the side channel `write`/`read` check instead.
+pub trait ErrorSink {
+    /// Drain and return any errors accumulated since the last drain.
+    fn take_errors(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl ErrorSink for revm::db::EmptyDB {}
+
+/// A [`DatabaseRef`] that lazily pulls account info, code and storage from a
+/// live chain over JSON-RPC, pinned to a single block.
+///
+/// Every read goes out over the wire the first time it's needed; callers are
+/// expected to wrap this in a [`revm::db::CacheDB`] so repeat reads of the
+/// same slot/account stay local after the first fetch.
+///
+/// `DatabaseRef` has no room for a `Result` the caller can inspect (revm
+/// expects `Infallible` here in the fork case), so failed RPC calls or decode
+/// errors are recorded in `errors` instead of being swallowed into a zeroed
+/// account, and `EthVmInner::write`/`read` turn them into an `Err` (via
+/// [`ErrorSink`]) once the call using them finishes.
+pub struct RpcDb {
+    client: Provider<Http>,
+    block: BlockId,
+    errors: RwLock<Vec<String>>,
+}
+
+impl RpcDb {
+    /// Connect to `rpc_url`, pinning all reads to `block_number`.
+    pub fn new(rpc_url: &str, block_number: u64) -> anyhow::Result<Self> {
+        let client = Provider::<Http>::try_from(rpc_url)?;
+
+        Ok(Self {
+            client,
+            block: BlockId::Number(BlockNumber::Number(block_number.into())),
+            errors: RwLock::new(Vec::new()),
+        })
+    }
+
+    /// Any RPC/decode errors accumulated since the last call to
+    /// [`RpcDb::take_errors`].
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.read().unwrap().clone()
+    }
+
+    fn record(&self, context: &str, err: impl std::fmt::Display) {
+        self.errors
+            .write()
+            .unwrap()
+            .push(format!("{context}: {err}"));
+    }
+
+    fn to_ethers(address: Address) -> ethers::types::Address {
+        ethers::types::Address::from(address.0 .0)
+    }
+
+    /// Run `fut` to completion on the shared [`rpc_worker`] thread, without
+    /// assuming anything about the calling thread. `DatabaseRef` methods are
+    /// plain sync fns that may themselves be invoked from inside an async
+    /// handler (this is, after all, meant to back simulations run from
+    /// services), and `Handle::block_on`/a stored `Runtime::block_on` both
+    /// panic with "Cannot start a runtime from within a runtime" in that
+    /// case. Handing the future to a dedicated worker thread sidesteps that
+    /// regardless of what the caller's thread is doing, and reusing the same
+    /// worker/runtime across calls avoids paying a thread-plus-runtime
+    /// spin-up for every account/storage touch.
+    fn block_on<F>(&self, fut: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let job: Job = Box::pin(async move {
+            let _ = tx.send(fut.await);
+        });
+
+        rpc_worker()
+            .send(job)
+            .expect("rpc worker thread is gone");
+        rx.recv().expect("rpc worker dropped the result channel")
+    }
+}
+
+/// A unit of work handed to [`rpc_worker`]: a future, boxed and type-erased
+/// since the worker's job queue carries every in-flight RPC call regardless
+/// of its output type. Each job is responsible for delivering its own result
+/// back to the caller (see [`RpcDb::block_on`]).
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The single background thread (and its long-lived current-thread Tokio
+/// runtime) that every [`RpcDb`] call runs its future on. Shared process-wide
+/// rather than per-`RpcDb`, since spinning up a fresh thread and runtime for
+/// every `basic`/`storage`/`block_hash` call would mean paying that cost once
+/// per account or storage slot touched during a simulated call.
+fn rpc_worker() -> &'static mpsc::Sender<Job> {
+    static SENDER: OnceLock<mpsc::Sender<Job>> = OnceLock::new();
+    SENDER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<Job>();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start rpc worker runtime");
+            for job in rx {
+                rt.block_on(job);
+            }
+        });
+        tx
+    })
+}
+
+impl ErrorSink for RpcDb {
+    fn take_errors(&self) -> Vec<String> {
+        std::mem::take(&mut self.errors.write().unwrap())
+    }
+}
+
+impl DatabaseRef for RpcDb {
+    type Error = std::convert::Infallible;
+
+    fn basic(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        let addr = Self::to_ethers(address);
+        let block = self.block;
+        let client = self.client.clone();
+
+        let fetched = self.block_on(async move {
+            tokio::try_join!(
+                client.get_balance(addr, Some(block)),
+                client.get_transaction_count(addr, Some(block)),
+                client.get_code(addr, Some(block)),
+            )
+        });
+
+        match fetched {
+            Ok((balance, nonce, code)) => {
+                let bytecode = Bytecode::new_raw(Bytes::from(code.to_vec()));
+                Ok(Some(AccountInfo {
+                    balance: U256::from_limbs(balance.0),
+                    nonce: nonce.as_u64(),
+                    code_hash: bytecode.hash_slow(),
+                    code: Some(bytecode),
+                }))
+            }
+            Err(e) => {
+                self.record(&format!("basic({address})"), e);
+                Ok(Some(AccountInfo::default()))
+            }
+        }
+    }
+
+    fn code_by_hash(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        // Code is always fetched and attached in `basic`, so this should
+        // only be hit for hashes we don't recognize (e.g. precompiles).
+        self.record(
+            "code_by_hash",
+            format!("unexpected lookup for {code_hash}, returning empty code"),
+        );
+        Ok(Bytecode::default())
+    }
+
+    fn storage(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        let addr = Self::to_ethers(address);
+        let slot: ethers::types::H256 = index.to_be_bytes::<32>().into();
+        let client = self.client.clone();
+        let block = self.block;
+
+        match self.block_on(async move { client.get_storage_at(addr, slot, Some(block)).await }) {
+            Ok(value) => Ok(U256::from_be_bytes(value.to_fixed_bytes())),
+            Err(e) => {
+                self.record(&format!("storage({address}, {index})"), e);
+                Ok(U256::ZERO)
+            }
+        }
+    }
+
+    fn block_hash(&self, number: U256) -> Result<B256, Self::Error> {
+        let number = number.to::<u64>();
+        let client = self.client.clone();
+
+        match self.block_on(async move { client.get_block(number).await }) {
+            Ok(Some(block)) => match block.hash {
+                Some(hash) => Ok(B256::from(hash.0)),
+                None => Ok(B256::ZERO),
+            },
+            Ok(None) => {
+                self.record("block_hash", format!("no block at height {number}"));
+                Ok(B256::ZERO)
+            }
+            Err(e) => {
+                self.record(&format!("block_hash({number})"), e);
+                Ok(B256::ZERO)
+            }
+        }
+    }
+}
@@ -1,10 +1,14 @@
 mod contract;
+mod db;
 mod provider;
+mod receipt;
 
 pub mod prelude {
     pub use super::contract::*;
 
-    pub use super::provider::RevmProvider;
+    pub use super::db::{ErrorSink, RpcDb};
+    pub use super::provider::{BlockConfig, RevmProvider, SnapshotId};
+    pub use super::receipt::{ContractCallError, FailureReason, Receipt};
 
     // for convenience
     pub use ethers::abi::parse_abi;
@@ -1,42 +1,157 @@
 use anyhow::bail;
 use revm::{
-    db::{CacheDB, DatabaseRef, DbAccount, EmptyDB},
+    db::{CacheDB, DatabaseCommit, DatabaseRef, DbAccount, EmptyDB},
     primitives::{
-        AccountInfo, Address, ExecutionResult, Log, Output, ResultAndState, TransactTo, TxEnv, U256,
+        Account, AccountInfo, Address, Bytecode, ExecutionResult, Halt, ResultAndState, TransactTo,
+        TxEnv, B256, U256,
     },
     EVM,
 };
 
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
+use crate::db::{ErrorSink, RpcDb};
+use crate::receipt::{build_receipt, Receipt};
+
+/// Id returned by [`RevmProvider::snapshot`] and accepted by
+/// [`RevmProvider::revert`].
+pub type SnapshotId = usize;
+
 /// Provider for Revm
+///
+/// Generic over the backing [`DatabaseRef`] so the same API works whether
+/// state is purely in-memory (the default, [`EmptyDB`]) or lazily forked
+/// from a live chain (see [`RevmProvider::fork`]).
 #[derive(Clone)]
-pub struct RevmProvider {
+pub struct RevmProvider<ExtDB: DatabaseRef = EmptyDB> {
     // use an inner approach so the provider does not need to be mutable
-    inner: Arc<RwLock<EthVmInner>>,
+    inner: Arc<RwLock<EthVmInner<ExtDB>>>,
 }
 
-// @todo need option to load fork from chain
-impl RevmProvider {
+impl RevmProvider<EmptyDB> {
     pub fn new() -> Self {
         Self {
-            inner: Arc::new(RwLock::new(EthVmInner::new())),
+            inner: Arc::new(RwLock::new(EthVmInner::new(EmptyDB {}))),
+        }
+    }
+
+    /// Construct with a starting block/chain environment instead of revm's
+    /// defaults. See [`BlockConfig`].
+    pub fn new_with_config(config: BlockConfig) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(EthVmInner::new_with_config(
+                EmptyDB {},
+                config,
+            ))),
+        }
+    }
+}
+
+impl RevmProvider<RpcDb> {
+    /// Fork state from a live chain at `block_number`, reached over JSON-RPC
+    /// at `rpc_url`. Account info, code and storage are fetched lazily and
+    /// cached locally, so only state actually touched by a `send`/`call` is
+    /// ever pulled over the network.
+    pub fn fork(rpc_url: &str, block_number: u64) -> anyhow::Result<Self> {
+        let db = RpcDb::new(rpc_url, block_number)?;
+        Ok(Self {
+            inner: Arc::new(RwLock::new(EthVmInner::new(db))),
+        })
+    }
+}
+
+/// Starting point for the block/chain environment a [`RevmProvider`] runs
+/// against, so contracts relying on `block.number`, `block.timestamp`,
+/// `block.basefee` or `chainid()` can be tested deterministically.
+///
+/// Build one with the setters (they consume and return `self`) and hand it
+/// to [`RevmProvider::new_with_config`]. To change the environment after
+/// construction, use the matching `RevmProvider::set_*`/`advance_block`
+/// methods instead, which write straight through to the live evm.
+#[derive(Clone, Debug)]
+pub struct BlockConfig {
+    block_number: U256,
+    timestamp: U256,
+    basefee: U256,
+    coinbase: Address,
+    chain_id: u64,
+    gas_limit: U256,
+}
+
+impl Default for BlockConfig {
+    fn default() -> Self {
+        Self {
+            block_number: U256::from(1),
+            timestamp: U256::from(1),
+            basefee: U256::ZERO,
+            coinbase: Address::ZERO,
+            chain_id: 1,
+            gas_limit: U256::MAX,
         }
     }
+}
+
+impl BlockConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    /// Deploy a contract. Return the contract's address and the amount of gas used
-    pub fn deploy(&self, tx: TxEnv) -> anyhow::Result<(Address, u64)> {
-        let (output, gas, _) = self
+    pub fn set_block_number(mut self, block_number: U256) -> Self {
+        self.block_number = block_number;
+        self
+    }
+
+    pub fn set_timestamp(mut self, timestamp: U256) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn set_basefee(mut self, basefee: U256) -> Self {
+        self.basefee = basefee;
+        self
+    }
+
+    pub fn set_coinbase(mut self, coinbase: Address) -> Self {
+        self.coinbase = coinbase;
+        self
+    }
+
+    pub fn set_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn set_gas_limit(mut self, gas_limit: U256) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+}
+
+impl<ExtDB: DatabaseRef + ErrorSink> RevmProvider<ExtDB>
+where
+    ExtDB::Error: std::fmt::Debug,
+{
+    /// Deploy a contract. Returns a [`Receipt`] with `created_address` set on
+    /// success; a reverted or halted deploy returns a receipt with `failure`
+    /// set instead.
+    pub fn deploy(&self, tx: TxEnv) -> anyhow::Result<Receipt> {
+        let receipt = self
             .inner
             .write()
             .unwrap()
             .write(tx)
-            .and_then(|r| process_execution_result(r))?;
+            .map(|(result, state)| build_receipt(result, state))?;
+
+        if receipt.failure.is_some() {
+            return Ok(receipt);
+        }
 
-        match output {
-            Output::Create(_, Some(address)) => Ok((address.into(), gas)),
-            _ => bail!("expected a create call"),
+        if receipt.created_address.is_none() {
+            bail!("expected a create call");
         }
+
+        Ok(receipt)
     }
 
     /// Transfer value
@@ -45,7 +160,7 @@ impl RevmProvider {
         from: T,
         to: T,
         value: U256,
-    ) -> anyhow::Result<(ethers::types::Bytes, u64, Vec<Log>)> {
+    ) -> anyhow::Result<Receipt> {
         let mut tx = TxEnv::default();
         tx.caller = from.into();
         tx.transact_to = TransactTo::Call(to.into());
@@ -54,22 +169,24 @@ impl RevmProvider {
         self.send(tx)
     }
 
-    /// Send a transaction. Committing to the Evm db
-    pub fn send(&self, tx: TxEnv) -> anyhow::Result<(ethers::types::Bytes, u64, Vec<Log>)> {
+    /// Send a transaction. Committing to the Evm db. `tx.gas_price` is
+    /// charged to the caller for the gas used, and a tx whose `gas_price` is
+    /// below the configured `block.basefee` (see [`BlockConfig`]) is rejected.
+    pub fn send(&self, tx: TxEnv) -> anyhow::Result<Receipt> {
         self.inner
             .write()
             .unwrap()
             .write(tx)
-            .and_then(|r| process_result_with_value(r))
+            .map(|(result, state)| build_receipt(result, state))
     }
 
     /// Call a contract (view, pure)
-    pub fn call(&self, tx: TxEnv) -> anyhow::Result<(ethers::types::Bytes, u64, Vec<Log>)> {
+    pub fn call(&self, tx: TxEnv) -> anyhow::Result<Receipt> {
         self.inner
             .write()
             .unwrap()
             .read(tx)
-            .and_then(|r| process_result_with_value(r))
+            .map(|(result, state)| build_receipt(result, state))
     }
 
     /// Get the balance for the given user
@@ -86,42 +203,190 @@ impl RevmProvider {
     pub fn view_account(&self, user: Address) -> anyhow::Result<DbAccount> {
         self.inner.write().unwrap().view_account(user)
     }
+
+    /// Estimate the gas a transaction needs to succeed, via binary search on
+    /// `tx.gas_limit`. Does not commit any state.
+    pub fn estimate_gas(&self, tx: TxEnv) -> anyhow::Result<u64> {
+        self.inner.write().unwrap().estimate_gas(tx)
+    }
+
+    /// Set `block.basefee` on the live environment.
+    pub fn set_basefee(&self, basefee: U256) {
+        self.inner.write().unwrap().evm.env.block.basefee = basefee;
+    }
+
+    /// Set `block.number` on the live environment.
+    pub fn set_block_number(&self, block_number: U256) {
+        self.inner.write().unwrap().evm.env.block.number = block_number;
+    }
+
+    /// Set `block.timestamp` on the live environment.
+    pub fn set_timestamp(&self, timestamp: U256) {
+        self.inner.write().unwrap().evm.env.block.timestamp = timestamp;
+    }
+
+    /// Set `block.coinbase` on the live environment.
+    pub fn set_coinbase(&self, coinbase: Address) {
+        self.inner.write().unwrap().evm.env.block.coinbase = coinbase;
+    }
+
+    /// Set the chain id used by `chainid()` on the live environment.
+    pub fn set_chain_id(&self, chain_id: u64) {
+        self.inner.write().unwrap().evm.env.cfg.chain_id = U256::from(chain_id);
+    }
+
+    /// Move the block forward by one, advancing the timestamp by a typical
+    /// 12s slot. Handy between test cases that need a fresh block.
+    pub fn advance_block(&self) {
+        let mut inner = self.inner.write().unwrap();
+        inner.evm.env.block.number += U256::from(1);
+        inner.evm.env.block.timestamp += U256::from(12);
+    }
+
+    /// Current `block.number` on the live environment.
+    pub fn block_number(&self) -> U256 {
+        self.inner.read().unwrap().evm.env.block.number
+    }
+
+    /// Current `block.timestamp` on the live environment.
+    pub fn timestamp(&self) -> U256 {
+        self.inner.read().unwrap().evm.env.block.timestamp
+    }
+
+    /// Current `block.basefee` on the live environment.
+    pub fn basefee(&self) -> U256 {
+        self.inner.read().unwrap().evm.env.block.basefee
+    }
+
+    /// Current `block.coinbase` on the live environment.
+    pub fn coinbase(&self) -> Address {
+        self.inner.read().unwrap().evm.env.block.coinbase
+    }
+
+    /// Current chain id used by `chainid()` on the live environment.
+    pub fn chain_id(&self) -> u64 {
+        self.inner
+            .read()
+            .unwrap()
+            .evm
+            .env
+            .cfg
+            .chain_id
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Snapshot the current state of the db, returning an id that can later
+    /// be passed to [`RevmProvider::revert`] to roll back to this point.
+    pub fn snapshot(&self) -> SnapshotId {
+        self.inner.write().unwrap().snapshot()
+    }
+
+    /// Roll the db back to a previously taken [`RevmProvider::snapshot`].
+    pub fn revert(&self, id: SnapshotId) -> anyhow::Result<()> {
+        self.inner.write().unwrap().revert(id)
+    }
 }
 
 // Inner wrapper talking to Revm
-struct EthVmInner {
-    evm: EVM<CacheDB<EmptyDB>>,
+struct EthVmInner<ExtDB: DatabaseRef> {
+    evm: EVM<CacheDB<ExtDB>>,
+    // stack of cached account/storage state, keyed by SnapshotId
+    snapshots: Vec<CacheDbState>,
+}
+
+// The cached account and contract state of a `CacheDB`, captured independent
+// of the backing `ExtDB` so a snapshot/revert doesn't require `ExtDB: Clone`
+// (and so forked accounts/storage that were already cached when the snapshot
+// was taken stay cached across a revert; anything fetched after it is dropped).
+#[derive(Clone)]
+struct CacheDbState {
+    accounts: HashMap<Address, DbAccount>,
+    contracts: HashMap<B256, Bytecode>,
 }
 
-impl EthVmInner {
-    fn new() -> Self {
+impl<ExtDB: DatabaseRef + ErrorSink> EthVmInner<ExtDB>
+where
+    ExtDB::Error: std::fmt::Debug,
+{
+    fn new(ext_db: ExtDB) -> Self {
         let mut evm = EVM::new();
-        let db = CacheDB::new(EmptyDB {});
+        let db = CacheDB::new(ext_db);
         evm.env.block.gas_limit = U256::MAX;
 
-        // @todo make configurable to include base fee,etc...
-        // evm.env.block.basefee = parse_ether(0.000001).unwrap().into();
-
         evm.database(db);
-        Self { evm }
+        Self {
+            evm,
+            snapshots: Vec::new(),
+        }
     }
 
-    /// write transaction to the db
-    fn write(&mut self, tx: TxEnv) -> anyhow::Result<ExecutionResult> {
+    fn new_with_config(ext_db: ExtDB, config: BlockConfig) -> Self {
+        let mut this = Self::new(ext_db);
+
+        this.evm.env.block.number = config.block_number;
+        this.evm.env.block.timestamp = config.timestamp;
+        this.evm.env.block.basefee = config.basefee;
+        this.evm.env.block.coinbase = config.coinbase;
+        this.evm.env.block.gas_limit = config.gas_limit;
+        this.evm.env.cfg.chain_id = U256::from(config.chain_id);
+
+        this
+    }
+
+    /// write transaction to the db, returning the touched state alongside
+    /// the result so callers can build a `Receipt`
+    fn write(&mut self, tx: TxEnv) -> anyhow::Result<(ExecutionResult, HashMap<Address, Account>)> {
         self.evm.env.tx = tx;
-        match self.evm.transact_commit() {
-            Ok(r) => Ok(r),
-            Err(e) => bail!(format!("error with write: {:?}", e)),
-        }
+        let ResultAndState { result, state } = self
+            .evm
+            .transact()
+            .map_err(|e| anyhow::anyhow!("error with write: {:?}", e))?;
+
+        // Check before committing: if the backing `ExtDB` recorded an error
+        // servicing this tx, `state` may hold zeroed/default fallback data
+        // (e.g. a failed RPC fetch in `RpcDb`), and `CacheDB` never re-queries
+        // an address it already holds — committing it would bake that bogus
+        // state into the cache permanently.
+        self.check_ext_db_errors()?;
+
+        self.evm.db().expect("evm db").commit(state.clone());
+        Ok((result, state))
     }
 
     /// read only
-    fn read(&mut self, tx: TxEnv) -> anyhow::Result<ExecutionResult> {
+    fn read(&mut self, tx: TxEnv) -> anyhow::Result<(ExecutionResult, HashMap<Address, Account>)> {
         self.evm.env.tx = tx;
-        match self.evm.transact_ref() {
-            Ok(ResultAndState { result, .. }) => Ok(result),
-            _ => bail!("error with simulate write..."),
+        let ResultAndState { result, state } = self.transact_ref_checked()?;
+        Ok((result, state))
+    }
+
+    /// Simulate the currently set `evm.env.tx` via `transact_ref`, then
+    /// surface any error the backing `ExtDB` recorded servicing it. Shared by
+    /// `read` and `estimate_gas`'s binary search so every probe against a
+    /// `fork()`ed provider gets the same error-surfacing guarantee `write`
+    /// has, rather than silently absorbing a failed RPC fetch into a zeroed
+    /// read and estimating against bogus state.
+    fn transact_ref_checked(&mut self) -> anyhow::Result<ResultAndState> {
+        let result_and_state = self
+            .evm
+            .transact_ref()
+            .map_err(|e| anyhow::anyhow!("error with simulate write: {:?}", e))?;
+
+        self.check_ext_db_errors()?;
+        Ok(result_and_state)
+    }
+
+    /// Surface any errors the backing `ExtDB` accumulated while servicing
+    /// the call just made (e.g. a failed RPC fetch in `RpcDb`), instead of
+    /// letting them hide behind the zeroed/default state `DatabaseRef`
+    /// silently returned for them.
+    fn check_ext_db_errors(&mut self) -> anyhow::Result<()> {
+        let errors = self.evm.db().expect("evm db").db.take_errors();
+        if !errors.is_empty() {
+            bail!("backing db reported error(s): {}", errors.join("; "));
         }
+        Ok(())
     }
 
     fn balance_of(&mut self, user: Address) -> U256 {
@@ -151,36 +416,110 @@ impl EthVmInner {
             _ => bail!("ooops"),
         }
     }
-}
 
-fn process_execution_result(result: ExecutionResult) -> anyhow::Result<(Output, u64, Vec<Log>)> {
-    match result {
-        ExecutionResult::Success {
-            output,
-            gas_used,
-            logs,
-            ..
-        } => Ok((output, gas_used, logs)),
-        ExecutionResult::Revert { output, .. } => bail!("Failed due to revert: {:?}", output),
-        ExecutionResult::Halt { reason, .. } => bail!("Failed due to halt: {:?}", reason),
+    fn snapshot(&mut self) -> SnapshotId {
+        let db = self.evm.db().expect("evm db");
+        self.snapshots.push(CacheDbState {
+            accounts: db.accounts.clone(),
+            contracts: db.contracts.clone(),
+        });
+        self.snapshots.len() - 1
+    }
+
+    fn revert(&mut self, id: SnapshotId) -> anyhow::Result<()> {
+        let state = self
+            .snapshots
+            .get(id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("no snapshot with id {id}"))?;
+
+        let db = self.evm.db().expect("evm db");
+        db.accounts = state.accounts;
+        db.contracts = state.contracts;
+        Ok(())
+    }
+
+    /// Binary search `tx.gas_limit` for the smallest value that lets the tx
+    /// succeed, simulating with `transact_ref` so nothing is committed.
+    fn estimate_gas(&mut self, mut tx: TxEnv) -> anyhow::Result<u64> {
+        let block_gas_limit: u64 = self.evm.env.block.gas_limit.try_into().unwrap_or(u64::MAX);
+
+        let mut lo = INTRINSIC_GAS;
+        let mut hi = block_gas_limit;
+
+        tx.gas_limit = hi;
+        self.evm.env.tx = tx.clone();
+        let at_limit = self.transact_ref_checked()?.result;
+
+        match gas_outcome(&at_limit) {
+            GasOutcome::Success(gas_used) => hi = gas_used,
+            GasOutcome::OutOfGas => bail!("transaction needs more than the block gas limit ({block_gas_limit})"),
+            GasOutcome::OtherFailure => bail!("transaction fails regardless of gas: {:?}", at_limit),
+        }
+
+        while hi.saturating_sub(lo) > GAS_ESTIMATE_TOLERANCE {
+            let mid = lo + (hi - lo) / 2;
+            tx.gas_limit = mid;
+            self.evm.env.tx = tx.clone();
+
+            let result = self.transact_ref_checked()?.result;
+
+            match gas_outcome(&result) {
+                GasOutcome::Success(gas_used) => hi = gas_used.min(mid),
+                GasOutcome::OutOfGas => lo = mid,
+                GasOutcome::OtherFailure => lo = mid,
+            }
+        }
+
+        // `hi` is usually the `gas_used` observed at some higher gas_limit,
+        // not a limit we've actually run the tx at — gas usage isn't always
+        // monotonic in gas_limit (e.g. `gasleft()`-dependent logic, or a
+        // sub-call getting less gas forwarded under EIP-150's 63/64 rule).
+        // Confirm it actually succeeds before handing it back.
+        tx.gas_limit = hi;
+        self.evm.env.tx = tx;
+        let confirmation = self.transact_ref_checked()?.result;
+
+        match gas_outcome(&confirmation) {
+            GasOutcome::Success(_) => Ok(hi),
+            _ => bail!(
+                "gas estimate of {hi} failed on confirmation ({:?}); gas usage for this \
+                 call is not monotonic in gas_limit",
+                confirmation
+            ),
+        }
     }
 }
 
-fn process_result_with_value(
-    result: ExecutionResult,
-) -> anyhow::Result<(ethers::types::Bytes, u64, Vec<Log>)> {
-    let (output, gas_used, logs) = process_execution_result(result)?;
-    let bits = match output {
-        Output::Call(value) => value,
-        _ => bail!("expected call output"),
-    };
+/// Minimum gas any transaction needs, used as the lower bound for
+/// `estimate_gas`'s binary search.
+const INTRINSIC_GAS: u64 = 21_000;
+
+/// How close the binary search window needs to get before we settle.
+const GAS_ESTIMATE_TOLERANCE: u64 = 1;
 
-    Ok((bits.into(), gas_used, logs))
+enum GasOutcome {
+    Success(u64),
+    OutOfGas,
+    OtherFailure,
+}
+
+fn gas_outcome(result: &ExecutionResult) -> GasOutcome {
+    match result {
+        ExecutionResult::Success { gas_used, .. } => GasOutcome::Success(*gas_used),
+        ExecutionResult::Halt {
+            reason: Halt::OutOfGas(_),
+            ..
+        } => GasOutcome::OutOfGas,
+        ExecutionResult::Halt { .. } => GasOutcome::OtherFailure,
+        ExecutionResult::Revert { .. } => GasOutcome::OtherFailure,
+    }
 }
 
 #[cfg(test)]
 mod test {
     use ethers::utils::parse_ether;
+    use revm::primitives::{TransactTo, TxEnv};
 
     use crate::prelude::*;
 
@@ -203,4 +542,107 @@ mod test {
         assert_eq!(provider.balance_of(bob), one_ether);
         assert_eq!(provider.balance_of(alice), one_ether);
     }
+
+    #[test]
+    fn estimate_gas_succeeds_when_replayed_at_the_estimate() {
+        let provider = RevmProvider::new();
+
+        let one_ether: U256 = parse_ether(1).unwrap().into();
+        let alice = Address::from_low_u64_be(1);
+        let bob = Address::from_low_u64_be(2);
+        provider.create_account(alice, Some(one_ether)).unwrap();
+
+        let mut tx = TxEnv::default();
+        tx.caller = alice;
+        tx.transact_to = TransactTo::Call(bob);
+        tx.value = U256::from(1);
+
+        let estimated = provider.estimate_gas(tx.clone()).expect("should estimate");
+        assert!(estimated >= 21_000, "a transfer needs at least the intrinsic 21000 gas");
+
+        tx.gas_limit = estimated;
+        let receipt = provider.send(tx).expect("send should not error");
+        assert!(
+            receipt.success(),
+            "the estimated gas limit should actually succeed when replayed"
+        );
+    }
+
+    #[test]
+    fn revert_restores_balance_from_before_the_snapshot() {
+        let provider = RevmProvider::new();
+
+        let one_ether: U256 = parse_ether(1).unwrap().into();
+        let alice = Address::from_low_u64_be(1);
+        let bob = Address::from_low_u64_be(2);
+        provider.create_account(alice, Some(one_ether)).unwrap();
+        provider.create_account(bob, None).unwrap();
+
+        let snapshot = provider.snapshot();
+
+        provider
+            .transfer(alice, bob, one_ether)
+            .expect("transfer should succeed");
+        assert_eq!(provider.balance_of(alice), U256::ZERO);
+        assert_eq!(provider.balance_of(bob), one_ether);
+
+        provider.revert(snapshot).expect("revert should succeed");
+
+        assert_eq!(provider.balance_of(alice), one_ether);
+        assert_eq!(provider.balance_of(bob), U256::ZERO);
+    }
+
+    #[test]
+    fn advance_block_bumps_number_and_timestamp_from_the_configured_start() {
+        let provider = RevmProvider::new_with_config(
+            BlockConfig::new()
+                .set_block_number(U256::from(10))
+                .set_timestamp(U256::from(100)),
+        );
+
+        provider.advance_block();
+
+        assert_eq!(provider.block_number(), U256::from(11));
+        assert_eq!(provider.timestamp(), U256::from(112));
+    }
+
+    #[test]
+    fn setters_write_through_to_the_live_environment() {
+        let provider = RevmProvider::new();
+
+        provider.set_block_number(U256::from(42));
+        provider.set_timestamp(U256::from(1000));
+        provider.set_basefee(U256::from(7));
+        provider.set_coinbase(Address::from_low_u64_be(9));
+        provider.set_chain_id(1337);
+
+        assert_eq!(provider.block_number(), U256::from(42));
+        assert_eq!(provider.timestamp(), U256::from(1000));
+        assert_eq!(provider.basefee(), U256::from(7));
+        assert_eq!(provider.coinbase(), Address::from_low_u64_be(9));
+        assert_eq!(provider.chain_id(), 1337);
+    }
+
+    #[test]
+    fn send_fails_when_gas_price_is_below_configured_basefee() {
+        let provider = RevmProvider::new();
+
+        let one_ether: U256 = parse_ether(1).unwrap().into();
+        let alice = Address::from_low_u64_be(1);
+        let bob = Address::from_low_u64_be(2);
+        provider.create_account(alice, Some(one_ether)).unwrap();
+
+        provider.set_basefee(U256::from(100));
+
+        let mut tx = TxEnv::default();
+        tx.caller = alice;
+        tx.transact_to = TransactTo::Call(bob);
+        tx.value = U256::from(1);
+        tx.gas_price = U256::from(1);
+
+        assert!(
+            provider.send(tx).is_err(),
+            "gas_price below the configured basefee should be rejected"
+        );
+    }
 }
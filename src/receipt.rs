@@ -0,0 +1,113 @@
+use revm::primitives::{Account, Address, Bytes, ExecutionResult, Halt, Log, Output, U256};
+use std::collections::HashMap;
+
+/// Outcome of a `send`/`call`/`deploy`, mirroring an Ethereum transaction
+/// receipt: gas spent, raw output, logs, created address (for a create
+/// call), and the touched accounts/storage slots pulled from the
+/// `ResultAndState.state` map that `transact`/`transact_ref` produce.
+///
+/// A revert or halt is not an `Err` here — it's a receipt like any other,
+/// with `failure` set to the structured reason instead of an opaque
+/// `anyhow` string.
+#[derive(Debug, Clone)]
+pub struct Receipt {
+    pub gas_used: u64,
+    pub output: Bytes,
+    pub created_address: Option<Address>,
+    pub logs: Vec<Log>,
+    pub failure: Option<FailureReason>,
+    /// Accounts touched during execution, mapped to the storage slots (if
+    /// any) touched on that account.
+    pub touched: HashMap<Address, Vec<U256>>,
+}
+
+impl Receipt {
+    pub fn success(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Why a transaction didn't succeed, in place of the revert/halt `bail!`
+/// strings `send`/`call`/`deploy` used to raise.
+#[derive(Debug, Clone)]
+pub enum FailureReason {
+    Revert(Bytes),
+    Halt(Halt),
+}
+
+/// Raised by `Contract::call`/`send` when the transaction reverted or
+/// halted, so the `Receipt` (gas used, logs, touched state) isn't lost the
+/// way an opaque `bail!` string would lose it. Downcast the `anyhow::Error`
+/// to get it back:
+///
+/// ```ignore
+/// match contract.send(&evm, "withdraw", (), caller, None) {
+///     Err(e) => match e.downcast_ref::<ContractCallError>() {
+///         Some(failed) => /* inspect failed.receipt */,
+///         None => /* some other error, e.g. bad ABI encoding */,
+///     },
+///     Ok((value, receipt)) => { /* ... */ }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractCallError {
+    pub receipt: Receipt,
+}
+
+impl std::fmt::Display for ContractCallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "call failed: {:?}", self.receipt.failure)
+    }
+}
+
+impl std::error::Error for ContractCallError {}
+
+pub(crate) fn build_receipt(result: ExecutionResult, state: HashMap<Address, Account>) -> Receipt {
+    let touched = state
+        .into_iter()
+        .map(|(address, account)| (address, account.storage.into_keys().collect()))
+        .collect();
+
+    match result {
+        ExecutionResult::Success {
+            output,
+            gas_used,
+            logs,
+            ..
+        } => {
+            let created_address = match &output {
+                Output::Create(_, Some(address)) => Some(*address),
+                _ => None,
+            };
+            let output = match output {
+                Output::Call(bytes) => bytes,
+                Output::Create(bytes, _) => bytes,
+            };
+
+            Receipt {
+                gas_used,
+                output,
+                created_address,
+                logs,
+                failure: None,
+                touched,
+            }
+        }
+        ExecutionResult::Revert { output, gas_used } => Receipt {
+            gas_used,
+            output: output.clone(),
+            created_address: None,
+            logs: Vec::new(),
+            failure: Some(FailureReason::Revert(output)),
+            touched,
+        },
+        ExecutionResult::Halt { reason, gas_used } => Receipt {
+            gas_used,
+            output: Bytes::default(),
+            created_address: None,
+            logs: Vec::new(),
+            failure: Some(FailureReason::Halt(reason)),
+            touched,
+        },
+    }
+}